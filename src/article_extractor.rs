@@ -1,93 +1,311 @@
+//! Descoped: `ArticleExtractorBuilder` does not expose TLS backend
+//! selection. Gating this crate's TLS backend behind cargo features
+//! (`default-tls`, `rustls-tls-webpki-roots`, `rustls-tls-native-roots`
+//! forwarding to reqwest's identically-named features) requires a
+//! `Cargo.toml` to declare those features in, and this source tree has
+//! none - there's nothing to wire the selection into. `Client::builder()`
+//! in [`ArticleExtractorBuilder::build`] links whatever TLS backend
+//! reqwest's own default features pull in (`native-tls`). Revisit once
+//! this crate has a manifest.
+
 use anyhow::{Context, Result};
-use reqwest::Client;
-use scraper::{Html, Selector};
+use async_trait::async_trait;
+use ego_tree::NodeId;
+use regex::Regex;
+use reqwest::{Client, StatusCode};
+use scraper::node::Element;
+use scraper::{ElementRef, Html, Selector};
+use std::collections::HashMap;
+use std::time::Duration;
 use url::Url;
 
+use crate::cookie_storage::CookieStorage;
 use crate::ArticleContent;
 
+const DEFAULT_USER_AGENT: &str = "Mozilla/5.0 (X11; Linux x86_64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/91.0.4472.124 Safari/537.36";
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+const DEFAULT_CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+const DEFAULT_MAX_RETRIES: u32 = 3;
+
+/// Login/subscriber credentials handed to [`SiteExtractor::authenticate`].
+#[derive(Debug, Clone, Default)]
+pub struct Credentials {
+    pub username: String,
+    pub password: String,
+}
+
+/// A pluggable per-site article extractor. Implementors decide whether they
+/// handle a given URL and, if so, how to pull title/author/content out of the
+/// parsed document. `ArticleExtractor` tries each registered `SiteExtractor`
+/// in order and falls back to `GenericExtractor` if none match.
+#[async_trait]
+pub trait SiteExtractor: Send + Sync {
+    /// Whether this extractor knows how to handle the given URL.
+    fn matches(&self, url: &Url) -> bool;
+
+    /// Pull an `ArticleContent` out of the already-fetched document.
+    fn extract(&self, document: &Html, url: &str) -> Result<ArticleContent>;
+
+    /// Establish a session before `fetch_html` runs - e.g. POST a login form
+    /// or dismiss an EU consent wall - so the next fetch sees the full
+    /// article instead of a paywall/consent page. Cookies set here persist
+    /// via whatever `CookieStorage` the `ArticleExtractor` was built with.
+    /// Default no-op for sites that don't need it.
+    async fn authenticate(&self, _client: &Client, _credentials: &Credentials) -> Result<()> {
+        Ok(())
+    }
+}
+
 pub struct ArticleExtractor {
     client: Client,
+    max_retries: u32,
+    user_agents: Vec<String>,
+    cookie_storage: Option<CookieStorage>,
+    extractors: Vec<Box<dyn SiteExtractor>>,
+    generic: GenericExtractor,
 }
 
 impl ArticleExtractor {
     pub fn new() -> Self {
-        let client = Client::builder()
-            .user_agent("Mozilla/5.0 (X11; Linux x86_64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/91.0.4472.124 Safari/537.36")
+        Self::builder()
             .build()
-            .expect("Failed to create HTTP client");
-        
-        Self { client }
+            .expect("Failed to create HTTP client")
     }
-    
+
+    /// Start building an `ArticleExtractor` with non-default timeouts,
+    /// retry behavior, or a rotating set of user agents.
+    pub fn builder() -> ArticleExtractorBuilder {
+        ArticleExtractorBuilder::new()
+    }
+
+    /// Register an additional site extractor, tried before the generic
+    /// fallback. Lets downstream users add their own publications (e.g. a
+    /// Reuters or local-news extractor) without forking the crate.
+    pub fn register(&mut self, extractor: Box<dyn SiteExtractor>) {
+        self.extractors.push(extractor);
+    }
+
+    /// Run the matching site extractor's login/consent-wall hook and persist
+    /// the resulting cookies, so a later `extract` call sees the full article
+    /// instead of a paywall or consent page.
+    pub async fn authenticate(&mut self, url: &str, credentials: &Credentials) -> Result<()> {
+        let parsed_url = Url::parse(url).context("Invalid URL provided")?;
+
+        if let Some(extractor) = self.extractors.iter().find(|e| e.matches(&parsed_url)) {
+            extractor.authenticate(&self.client, credentials).await?;
+        }
+
+        if let Some(storage) = &self.cookie_storage {
+            storage.persist()?;
+        }
+
+        Ok(())
+    }
+
     pub async fn extract(&mut self, url: &str) -> Result<ArticleContent> {
-        let _parsed_url = Url::parse(url)
+        let parsed_url = Url::parse(url)
             .context("Invalid URL provided")?;
-        
+
         let html = self.fetch_html(url).await?;
         let document = Html::parse_document(&html);
-        
-        // Try multiple extraction strategies based on the website
-        let content = if url.contains("theguardian.com") {
-            self.extract_guardian_article(&document, url)?
-        } else if url.contains("bbc.co.uk") || url.contains("bbc.com") {
-            self.extract_bbc_article(&document, url)?
-        } else if url.contains("nytimes.com") {
-            self.extract_nytimes_article(&document, url)?
-        } else {
-            // Generic extraction for other sites
-            self.extract_generic_article(&document, url)?
-        };
-        
-        Ok(content)
+
+        // Try each registered site extractor in order, falling back to the
+        // generic density-based extractor if none of them match.
+        match self.extractors.iter().find(|e| e.matches(&parsed_url)) {
+            Some(extractor) => extractor.extract(&document, url),
+            None => self.generic.extract(&document, url),
+        }
     }
-    
+
+    /// Fetch a URL, retrying on transient network errors and 429/5xx
+    /// responses with exponential backoff (honoring `Retry-After` when the
+    /// server sends one) before giving up after `max_retries` retries.
     async fn fetch_html(&self, url: &str) -> Result<String> {
-        let response = self.client
-            .get(url)
-            .send()
-            .await
-            .context("Failed to fetch URL")?;
-        
-        if !response.status().is_success() {
-            return Err(anyhow::anyhow!("HTTP error: {}", response.status()));
+        let mut attempt: u32 = 0;
+
+        loop {
+            let user_agent = &self.user_agents[attempt as usize % self.user_agents.len()];
+            let result = self.client
+                .get(url)
+                .header("User-Agent", user_agent)
+                .send()
+                .await;
+
+            match result {
+                Ok(response) if response.status().is_success() => {
+                    return response
+                        .text()
+                        .await
+                        .context("Failed to read response body");
+                }
+                Ok(response) if is_retryable_status(response.status()) && attempt < self.max_retries => {
+                    let delay = retry_delay(&response, attempt);
+                    attempt += 1;
+                    tokio::time::sleep(delay).await;
+                }
+                Ok(response) => {
+                    return Err(anyhow::anyhow!("HTTP error: {}", response.status()));
+                }
+                Err(_) if attempt < self.max_retries => {
+                    attempt += 1;
+                    tokio::time::sleep(backoff_delay(attempt)).await;
+                }
+                Err(e) => return Err(e).context("Failed to fetch URL"),
+            }
         }
-        
-        let html = response
-            .text()
-            .await
-            .context("Failed to read response body")?;
-        
-        Ok(html)
     }
-    
-    fn extract_guardian_article(&self, document: &Html, url: &str) -> Result<ArticleContent> {
+}
+
+impl Default for ArticleExtractor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Builds an [`ArticleExtractor`] with a configurable timeout, retry count,
+/// and user-agent rotation. TLS backend selection is out of scope for now -
+/// see the module docs at the top of this file.
+pub struct ArticleExtractorBuilder {
+    timeout: Duration,
+    connect_timeout: Duration,
+    max_retries: u32,
+    user_agents: Vec<String>,
+    cookie_storage: Option<CookieStorage>,
+}
+
+impl ArticleExtractorBuilder {
+    fn new() -> Self {
+        Self {
+            timeout: DEFAULT_TIMEOUT,
+            connect_timeout: DEFAULT_CONNECT_TIMEOUT,
+            max_retries: DEFAULT_MAX_RETRIES,
+            user_agents: vec![DEFAULT_USER_AGENT.to_string()],
+            cookie_storage: None,
+        }
+    }
+
+    /// Per-request timeout, covering the full request/response cycle.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Timeout for establishing the TCP/TLS connection.
+    pub fn connect_timeout(mut self, connect_timeout: Duration) -> Self {
+        self.connect_timeout = connect_timeout;
+        self
+    }
+
+    /// Number of retries on transient network errors and 429/5xx responses.
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// User agents to rotate through across retries of the same request.
+    pub fn user_agents(mut self, user_agents: Vec<String>) -> Self {
+        if !user_agents.is_empty() {
+            self.user_agents = user_agents;
+        }
+        self
+    }
+
+    /// Persist cookies (consent-wall dismissals, login sessions) across runs
+    /// using the given [`CookieStorage`].
+    pub fn cookie_storage(mut self, cookie_storage: CookieStorage) -> Self {
+        self.cookie_storage = Some(cookie_storage);
+        self
+    }
+
+    pub fn build(self) -> Result<ArticleExtractor> {
+        let mut client_builder = Client::builder()
+            .user_agent(self.user_agents[0].clone())
+            .timeout(self.timeout)
+            .connect_timeout(self.connect_timeout);
+
+        if let Some(storage) = &self.cookie_storage {
+            client_builder = client_builder.cookie_provider(storage.store());
+        }
+
+        let client = client_builder
+            .build()
+            .context("Failed to create HTTP client")?;
+
+        Ok(ArticleExtractor {
+            client,
+            max_retries: self.max_retries,
+            user_agents: self.user_agents,
+            cookie_storage: self.cookie_storage,
+            extractors: vec![
+                Box::new(GuardianExtractor),
+                Box::new(BbcExtractor),
+                Box::new(NytimesExtractor),
+            ],
+            generic: GenericExtractor,
+        })
+    }
+}
+
+fn is_retryable_status(status: StatusCode) -> bool {
+    status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+}
+
+/// Exponential backoff (500ms, 1s, 2s, ...), unless the server told us
+/// exactly how long to wait via `Retry-After`.
+fn retry_delay(response: &reqwest::Response, attempt: u32) -> Duration {
+    response
+        .headers()
+        .get(reqwest::header::RETRY_AFTER)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.parse::<u64>().ok())
+        .map(Duration::from_secs)
+        .unwrap_or_else(|| backoff_delay(attempt + 1))
+}
+
+fn backoff_delay(attempt: u32) -> Duration {
+    Duration::from_millis(250 * 2u64.pow(attempt.min(6)))
+}
+
+fn host_contains(url: &Url, needle: &str) -> bool {
+    url.host_str().map(|h| h.contains(needle)).unwrap_or(false)
+}
+
+struct GuardianExtractor;
+
+#[async_trait]
+impl SiteExtractor for GuardianExtractor {
+    fn matches(&self, url: &Url) -> bool {
+        host_contains(url, "theguardian.com")
+    }
+
+    fn extract(&self, document: &Html, url: &str) -> Result<ArticleContent> {
         // Guardian-specific selectors - updated for current Guardian layout
         let title_selector = Selector::parse("h1[data-gu-name='headline'], h1.content__headline, h1")
             .map_err(|e| anyhow::anyhow!("Invalid selector: {:?}", e))?;
-        
+
         let author_selector = Selector::parse("a[rel='author'], .byline a, .contributor-full-name")
             .map_err(|e| anyhow::anyhow!("Invalid selector: {:?}", e))?;
-        
+
         let date_selector = Selector::parse("time[datetime], .content__dateline time")
             .map_err(|e| anyhow::anyhow!("Invalid selector: {:?}", e))?;
-        
+
         let content_selector = Selector::parse(".content__article-body p, .article-body-commercial-selector p, [data-gu-name='body'] p")
             .map_err(|e| anyhow::anyhow!("Invalid selector: {:?}", e))?;
-        
+
         // Extract title
         let title = document
             .select(&title_selector)
             .next()
             .map(|el| el.text().collect::<String>().trim().to_string())
             .unwrap_or_else(|| "Untitled Article".to_string());
-        
+
         // Extract author
         let author = document
             .select(&author_selector)
             .next()
             .map(|el| el.text().collect::<String>().trim().to_string())
             .filter(|s| !s.is_empty());
-        
+
         // Extract publication date
         let published_date = document
             .select(&date_selector)
@@ -95,7 +313,7 @@ impl ArticleExtractor {
             .and_then(|el| el.value().attr("datetime").or_else(|| el.text().collect::<Vec<_>>().first().copied()))
             .map(|s| s.trim().to_string())
             .filter(|s| !s.is_empty());
-        
+
         // Extract content paragraphs
         let content_paragraphs: Vec<String> = document
             .select(&content_selector)
@@ -105,13 +323,13 @@ impl ArticleExtractor {
             })
             .filter(|p| !p.is_empty() && p.len() > 10) // Filter out very short paragraphs
             .collect();
-        
+
         let content = content_paragraphs.join("\n\n");
-        
+
         if content.is_empty() {
             return Err(anyhow::anyhow!("No article content found"));
         }
-        
+
         Ok(ArticleContent {
             title,
             author,
@@ -121,41 +339,50 @@ impl ArticleExtractor {
             url: url.to_string(),
         })
     }
-    
-    fn extract_bbc_article(&self, document: &Html, url: &str) -> Result<ArticleContent> {
+}
+
+struct BbcExtractor;
+
+#[async_trait]
+impl SiteExtractor for BbcExtractor {
+    fn matches(&self, url: &Url) -> bool {
+        host_contains(url, "bbc.co.uk") || host_contains(url, "bbc.com")
+    }
+
+    fn extract(&self, document: &Html, url: &str) -> Result<ArticleContent> {
         let title_selector = Selector::parse("h1.story-body__h1, h1[data-testid='headline']")
             .map_err(|e| anyhow::anyhow!("Invalid selector: {:?}", e))?;
-        
+
         let content_selector = Selector::parse(".story-body__inner p, [data-component='text-block'] p")
             .map_err(|e| anyhow::anyhow!("Invalid selector: {:?}", e))?;
-        
+
         let date_selector = Selector::parse("time[datetime], .date")
             .map_err(|e| anyhow::anyhow!("Invalid selector: {:?}", e))?;
-        
+
         let title = document
             .select(&title_selector)
             .next()
             .map(|el| el.text().collect::<String>().trim().to_string())
             .unwrap_or_else(|| "BBC Article".to_string());
-        
+
         let published_date = document
             .select(&date_selector)
             .next()
             .and_then(|el| el.value().attr("datetime"))
             .map(|s| s.to_string());
-        
+
         let content_paragraphs: Vec<String> = document
             .select(&content_selector)
             .map(|el| el.text().collect::<String>().trim().to_string())
             .filter(|p| !p.is_empty() && p.len() > 10)
             .collect();
-        
+
         let content = content_paragraphs.join("\n\n");
-        
+
         if content.is_empty() {
             return Err(anyhow::anyhow!("No BBC article content found"));
         }
-        
+
         Ok(ArticleContent {
             title,
             author: None,
@@ -165,41 +392,50 @@ impl ArticleExtractor {
             url: url.to_string(),
         })
     }
-    
-    fn extract_nytimes_article(&self, document: &Html, url: &str) -> Result<ArticleContent> {
+}
+
+struct NytimesExtractor;
+
+#[async_trait]
+impl SiteExtractor for NytimesExtractor {
+    fn matches(&self, url: &Url) -> bool {
+        host_contains(url, "nytimes.com")
+    }
+
+    fn extract(&self, document: &Html, url: &str) -> Result<ArticleContent> {
         let title_selector = Selector::parse("h1[data-testid='headline'], h1.headline")
             .map_err(|e| anyhow::anyhow!("Invalid selector: {:?}", e))?;
-        
+
         let author_selector = Selector::parse("[data-testid='byline'] span, .byline-author")
             .map_err(|e| anyhow::anyhow!("Invalid selector: {:?}", e))?;
-        
+
         let content_selector = Selector::parse(".StoryBodyCompanionColumn p, section[name='articleBody'] p")
             .map_err(|e| anyhow::anyhow!("Invalid selector: {:?}", e))?;
-        
+
         let title = document
             .select(&title_selector)
             .next()
             .map(|el| el.text().collect::<String>().trim().to_string())
             .unwrap_or_else(|| "New York Times Article".to_string());
-        
+
         let author = document
             .select(&author_selector)
             .next()
             .map(|el| el.text().collect::<String>().trim().to_string())
             .filter(|s| !s.is_empty());
-        
+
         let content_paragraphs: Vec<String> = document
             .select(&content_selector)
             .map(|el| el.text().collect::<String>().trim().to_string())
             .filter(|p| !p.is_empty() && p.len() > 10)
             .collect();
-        
+
         let content = content_paragraphs.join("\n\n");
-        
+
         if content.is_empty() {
             return Err(anyhow::anyhow!("No NYT article content found"));
         }
-        
+
         Ok(ArticleContent {
             title,
             author,
@@ -209,22 +445,56 @@ impl ArticleExtractor {
             url: url.to_string(),
         })
     }
-    
-    fn extract_generic_article(&self, document: &Html, url: &str) -> Result<ArticleContent> {
-        // Generic extraction using common patterns
+
+    async fn authenticate(&self, client: &Client, credentials: &Credentials) -> Result<()> {
+        if credentials.username.is_empty() || credentials.password.is_empty() {
+            return Ok(());
+        }
+
+        // Logs the subscriber session into the client's cookie jar so the
+        // next fetch of a metered article sees the full text.
+        let response = client
+            .post("https://myaccount.nytimes.com/svc/ios/v2/login")
+            .form(&[
+                ("login", credentials.username.as_str()),
+                ("password", credentials.password.as_str()),
+            ])
+            .send()
+            .await
+            .context("Failed to submit New York Times login form")?;
+
+        if !response.status().is_success() {
+            return Err(anyhow::anyhow!(
+                "New York Times login failed: {}",
+                response.status()
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+/// Fallback extractor used when no site-specific extractor matches. Finds
+/// title/author via common markup patterns and content via Readability-style
+/// density scoring, so arbitrary domains get a reasonable extraction without
+/// per-site maintenance.
+struct GenericExtractor;
+
+#[async_trait]
+impl SiteExtractor for GenericExtractor {
+    fn matches(&self, _url: &Url) -> bool {
+        true
+    }
+
+    fn extract(&self, document: &Html, url: &str) -> Result<ArticleContent> {
         let title_selectors = vec![
             "h1", "title", ".title", ".headline", ".entry-title", ".post-title"
         ];
-        
-        let content_selectors = vec![
-            "article p", ".content p", ".entry-content p", ".post-content p", 
-            ".article-body p", "main p", ".story p"
-        ];
-        
+
         let author_selectors = vec![
             ".author", ".byline", ".writer", "[rel='author']"
         ];
-        
+
         // Try to find title
         let mut title = String::new();
         for selector_str in title_selectors {
@@ -237,11 +507,11 @@ impl ArticleExtractor {
                 }
             }
         }
-        
+
         if title.is_empty() {
             title = "Article".to_string();
         }
-        
+
         // Try to find author
         let mut author = None;
         for selector_str in author_selectors {
@@ -255,29 +525,13 @@ impl ArticleExtractor {
                 }
             }
         }
-        
-        // Try to find content
-        let mut content_paragraphs = Vec::new();
-        for selector_str in content_selectors {
-            if let Ok(selector) = Selector::parse(selector_str) {
-                content_paragraphs = document
-                    .select(&selector)
-                    .map(|el| el.text().collect::<String>().trim().to_string())
-                    .filter(|p| !p.is_empty() && p.len() > 20)
-                    .collect();
-                
-                if !content_paragraphs.is_empty() {
-                    break;
-                }
-            }
-        }
-        
-        let content = content_paragraphs.join("\n\n");
-        
+
+        let content = extract_content_by_density(document)?;
+
         if content.is_empty() {
             return Err(anyhow::anyhow!("Could not extract article content from this page"));
         }
-        
+
         Ok(ArticleContent {
             title,
             author,
@@ -289,8 +543,144 @@ impl ArticleExtractor {
     }
 }
 
-impl Default for ArticleExtractor {
-    fn default() -> Self {
-        Self::new()
+/// Readability-style content extraction: score every paragraph-like node,
+/// propagate that score up to its parent and grandparent, then penalize
+/// candidates that are mostly links and pick the highest scorer.
+fn extract_content_by_density(document: &Html) -> Result<String> {
+    let paragraph_selector = Selector::parse("p, td, pre")
+        .map_err(|e| anyhow::anyhow!("Invalid selector: {:?}", e))?;
+    let link_selector = Selector::parse("a")
+        .map_err(|e| anyhow::anyhow!("Invalid selector: {:?}", e))?;
+
+    let mut scores: HashMap<NodeId, f64> = HashMap::new();
+
+    for node in document.select(&paragraph_selector) {
+        let text = node.text().collect::<String>();
+        let text = text.trim();
+        if text.len() < 25 {
+            continue; // too short to carry any useful signal
+        }
+
+        let mut content_score = 1.0;
+        content_score += text.matches(',').count() as f64;
+        content_score += (text.len() as f64 / 100.0).min(3.0);
+
+        if let Some(parent) = node.parent().and_then(ElementRef::wrap) {
+            *scores
+                .entry(parent.id())
+                .or_insert_with(|| base_candidate_score(parent.value())) += content_score;
+
+            if let Some(grandparent) = parent.parent().and_then(ElementRef::wrap) {
+                *scores
+                    .entry(grandparent.id())
+                    .or_insert_with(|| base_candidate_score(grandparent.value())) +=
+                    content_score / 2.0;
+            }
+        }
+    }
+
+    let best = scores
+        .into_iter()
+        .filter_map(|(id, score)| {
+            let element = ElementRef::wrap(document.tree.get(id)?)?;
+            let adjusted = score * (1.0 - link_density(element, &link_selector));
+            Some((element, adjusted))
+        })
+        .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal));
+
+    let Some((best_element, _)) = best else {
+        return Ok(String::new());
+    };
+
+    let content_paragraphs: Vec<String> = best_element
+        .select(&paragraph_selector)
+        .map(|el| el.text().collect::<String>().trim().to_string())
+        .filter(|p| !p.is_empty() && p.len() > 20)
+        .collect();
+
+    Ok(content_paragraphs.join("\n\n"))
+}
+
+/// Initial weight for a candidate node before any paragraph scores are
+/// propagated into it, based on how likely the tag is to hold real content.
+fn base_candidate_score(element: &Element) -> f64 {
+    let tag_score = match element.name() {
+        "div" | "article" | "section" => 0.0,
+        "pre" | "td" | "blockquote" => -2.0,
+        "address" | "ol" | "ul" | "dl" | "dd" | "dt" | "li" | "form" => -3.0,
+        "h1" | "h2" | "h3" | "h4" | "h5" | "h6" | "th" => -5.0,
+        _ => 0.0,
+    };
+
+    tag_score + class_id_weight(element)
+}
+
+/// Bonus/penalty derived from the `class`/`id` attributes, mirroring the
+/// regexes the original Readability algorithm uses to spot boilerplate.
+fn class_id_weight(element: &Element) -> f64 {
+    let positive = Regex::new(r"(?i)article|body|content|entry|post").unwrap();
+    let negative = Regex::new(r"(?i)comment|sidebar|footer|nav|share|promo|ad").unwrap();
+
+    let mut weight = 0.0;
+    for attr in ["class", "id"] {
+        if let Some(value) = element.attr(attr) {
+            if positive.is_match(value) {
+                weight += 25.0;
+            }
+            if negative.is_match(value) {
+                weight -= 25.0;
+            }
+        }
+    }
+    weight
+}
+
+/// Fraction of a candidate's text that sits inside `<a>` tags - a high ratio
+/// usually means a nav/related-links block rather than article prose.
+fn link_density(element: ElementRef, link_selector: &Selector) -> f64 {
+    let text_len = element.text().collect::<String>().len();
+    if text_len == 0 {
+        return 0.0;
+    }
+
+    let link_len: usize = element
+        .select(link_selector)
+        .map(|a| a.text().collect::<String>().len())
+        .sum();
+
+    link_len as f64 / text_len as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn picks_the_dense_article_body_over_a_linky_sidebar() {
+        let html = r#"
+            <html><body>
+                <nav class="sidebar">
+                    <p><a href="/a">Link one</a> <a href="/b">Link two</a> <a href="/c">Link three</a></p>
+                </nav>
+                <article class="article-content">
+                    <p>This is the first real paragraph of the article, long enough to score.</p>
+                    <p>This is the second real paragraph, also long enough to carry signal.</p>
+                </article>
+            </body></html>
+        "#;
+
+        let document = Html::parse_document(html);
+        let content = extract_content_by_density(&document).unwrap();
+
+        assert!(content.contains("first real paragraph"));
+        assert!(content.contains("second real paragraph"));
+        assert!(!content.contains("Link one"));
+    }
+
+    #[test]
+    fn returns_empty_string_when_nothing_scores() {
+        let document = Html::parse_document("<html><body><p>short</p></body></html>");
+        let content = extract_content_by_density(&document).unwrap();
+        assert!(content.is_empty());
     }
 }
@@ -1,75 +1,154 @@
 use anyhow::{Context, Result};
+use gstreamer as gst;
+use gstreamer::prelude::*;
+use gstreamer_app::{AppSink, AppSrc, AppSinkCallbacks};
 use std::fs::File;
 use std::io::{BufWriter, Write};
 use std::path::Path;
+use std::sync::{Arc, Mutex};
 
 use crate::AudioConfig;
 
+/// WhatsApp's media size limit; `optimize_for_whatsapp` re-encodes at
+/// decreasing bitrates until the output fits under this many bytes.
+const DEFAULT_SIZE_BUDGET_BYTES: u64 = 16 * 1024 * 1024;
+
+/// Bitrates (kbps, mono) tried in order, from best to worst quality, until
+/// the re-encoded audio fits the size budget.
+const BITRATE_LADDER_KBPS: &[u32] = &[48, 32, 24];
+
 pub struct AudioProcessor {
-    // Future: Could add audio processing capabilities
+    size_budget_bytes: u64,
 }
 
 impl AudioProcessor {
     pub fn new() -> Self {
-        Self {}
+        Self {
+            size_budget_bytes: DEFAULT_SIZE_BUDGET_BYTES,
+        }
+    }
+
+    /// Override the default WhatsApp size budget (16MB), e.g. for a
+    /// different target platform's media limit.
+    pub fn with_size_budget_bytes(mut self, size_budget_bytes: u64) -> Self {
+        self.size_budget_bytes = size_budget_bytes;
+        self
     }
-    
-    pub fn save_optimized_audio(&self, audio_data: &[u8], output_path: &Path, _config: &AudioConfig) -> Result<()> {
-        // For now, save the audio directly since cloud TTS services already provide optimized MP3
-        // In the future, we could add compression, normalization, etc.
-        
+
+    pub fn save_optimized_audio(&self, audio_data: &[u8], output_path: &Path, config: &AudioConfig) -> Result<()> {
+        let optimized = self
+            .optimize_for_whatsapp(audio_data, &config.output_format)
+            .context("Failed to optimize audio for WhatsApp")?;
+
         let file = File::create(output_path)
             .with_context(|| format!("Failed to create output file: {}", output_path.display()))?;
-        
+
         let mut writer = BufWriter::new(file);
-        writer.write_all(audio_data)
+        writer.write_all(&optimized)
             .context("Failed to write audio data to file")?;
-        
+
         writer.flush()
             .context("Failed to flush audio data to file")?;
-        
-        // Validate file size for WhatsApp compatibility
+
         let file_size = std::fs::metadata(output_path)
             .context("Failed to get file metadata")?
             .len();
-        
-        // WhatsApp has a 16MB limit for media files
-        const WHATSAPP_LIMIT: u64 = 16 * 1024 * 1024; // 16MB
-        
-        if file_size > WHATSAPP_LIMIT {
-            eprintln!("⚠️  Warning: Audio file size ({:.1}MB) exceeds WhatsApp's 16MB limit.", 
+
+        let budget_mb = self.size_budget_bytes as f64 / (1024.0 * 1024.0);
+        if file_size > self.size_budget_bytes {
+            eprintln!("⚠️  Warning: Audio file size ({:.1}MB) exceeds the {budget_mb:.1}MB size budget after re-encoding.",
                      file_size as f64 / (1024.0 * 1024.0));
-            eprintln!("   Consider reducing the article length or using a lower quality setting.");
+            eprintln!("   Consider reducing the article length.");
         } else {
-            println!("📊 Audio file size: {:.1}MB (WhatsApp compatible)", 
+            println!("📊 Audio file size: {:.1}MB (WhatsApp compatible)",
                     file_size as f64 / (1024.0 * 1024.0));
         }
-        
+
+        if let Ok(analysis) = self.analyze_audio_quality(&optimized) {
+            println!(
+                "🔊 Duration: {:.1}s, avg volume: {:.0}%, peak: {:.0}%, quality score: {:.2}",
+                analysis.duration_seconds,
+                analysis.average_volume * 100.0,
+                analysis.peak_volume * 100.0,
+                analysis.quality_score
+            );
+        }
+
         Ok(())
     }
-    
-    // Future enhancement: Audio compression and optimization
-    #[allow(dead_code)]
-    fn optimize_for_whatsapp(&self, audio_data: &[u8]) -> Result<Vec<u8>> {
-        // Placeholder for future audio optimization features:
-        // - Reduce bitrate if file is too large
-        // - Normalize audio levels
-        // - Remove silence at beginning/end
-        // - Apply noise reduction
-        
-        // For now, return the original data
-        Ok(audio_data.to_vec())
+
+    /// Decode the incoming audio, trim leading/trailing silence, normalize
+    /// loudness, then re-encode as `output_format` at decreasing bitrates
+    /// (see [`BITRATE_LADDER_KBPS`]) until the result fits
+    /// `size_budget_bytes`. Falls back to the last (smallest) encoding if
+    /// none fit. `wav` is lossless and PCM size doesn't shrink with
+    /// "bitrate", so it skips loudness normalization and the ladder: the
+    /// silence-trimmed audio is returned as a 24kHz mono 16-bit WAV.
+    fn optimize_for_whatsapp(&self, audio_data: &[u8], output_format: &str) -> Result<Vec<u8>> {
+        let (samples, sample_rate) = decode_to_mono_pcm(audio_data)
+            .context("Failed to decode audio for silence trimming")?;
+        let trimmed = encode_wav_mono_s16le(trim_silence(&samples), sample_rate);
+
+        if output_format == "wav" {
+            return Ok(trimmed);
+        }
+
+        // FLAC is lossless: bitrate doesn't affect its size, so stepping
+        // down the ladder would just re-encode the same output repeatedly.
+        let ladder: &[u32] = if output_format == "flac" { &BITRATE_LADDER_KBPS[..1] } else { BITRATE_LADDER_KBPS };
+
+        let mut best = None;
+
+        for &bitrate_kbps in ladder {
+            let encoded = transcode_pipeline(&trimmed, bitrate_kbps, output_format)
+                .with_context(|| format!("Failed to transcode audio to {} at {}kbps", output_format, bitrate_kbps))?;
+
+            let fits = (encoded.len() as u64) <= self.size_budget_bytes;
+            best = Some(encoded);
+
+            if fits {
+                break;
+            }
+        }
+
+        best.context("Bitrate ladder is empty")
     }
-    
-    // Future enhancement: Audio analysis
-    #[allow(dead_code)]
-    fn analyze_audio_quality(&self, _audio_data: &[u8]) -> Result<AudioAnalysis> {
-        // Placeholder for audio quality analysis
+
+    /// Decode `audio_data` to raw samples and derive duration, volume, and a
+    /// simple quality score (rewards audio that isn't clipped or silent).
+    fn analyze_audio_quality(&self, audio_data: &[u8]) -> Result<AudioAnalysis> {
+        let (samples, sample_rate) = decode_to_mono_pcm(audio_data)?;
+
+        if samples.is_empty() || sample_rate == 0 {
+            return Ok(AudioAnalysis {
+                duration_seconds: 0.0,
+                average_volume: 0.0,
+                peak_volume: 0.0,
+                quality_score: 0.0,
+            });
+        }
+
+        let duration_seconds = samples.len() as f32 / sample_rate as f32;
+
+        let sum_abs: f64 = samples.iter().map(|&s| (s as f64).abs()).sum();
+        let average_volume = (sum_abs / samples.len() as f64 / i16::MAX as f64) as f32;
+
+        let peak = samples.iter().map(|&s| (s as i32).unsigned_abs()).max().unwrap_or(0);
+        let peak_volume = peak as f32 / i16::MAX as f32;
+
+        // Penalize near-silent audio (likely a synthesis failure) and
+        // clipping (peak pinned at full scale); reward everything between.
+        let quality_score = if peak_volume > 0.99 {
+            0.5
+        } else {
+            (average_volume * 4.0).clamp(0.0, 1.0)
+        };
+
         Ok(AudioAnalysis {
-            duration_seconds: 0.0,
-            average_volume: 0.0,
-            peak_volume: 0.0,
-            quality_score: 1.0,
+            duration_seconds,
+            average_volume,
+            peak_volume,
+            quality_score,
         })
     }
 }
@@ -80,10 +159,218 @@ impl Default for AudioProcessor {
     }
 }
 
-#[allow(dead_code)]
-struct AudioAnalysis {
-    duration_seconds: f32,
-    average_volume: f32,
-    peak_volume: f32,
-    quality_score: f32,
+#[derive(Debug, Clone, Copy)]
+pub struct AudioAnalysis {
+    pub duration_seconds: f32,
+    pub average_volume: f32,
+    pub peak_volume: f32,
+    pub quality_score: f32,
+}
+
+/// Run `audio_data` through a GStreamer pipeline that decodes it, normalizes
+/// loudness (`rganalysis` computes the ReplayGain tags that `rgvolume` then
+/// applies - `rgvolume` alone is a no-op on untagged input), and re-encodes
+/// it as mono `output_format` at `bitrate_kbps` (one of OpenAI's
+/// `OPENAI_RESPONSE_FORMATS`, except `wav`, which bypasses this pipeline
+/// entirely - see `optimize_for_whatsapp`). Falls back to MP3 for an
+/// unrecognized format.
+fn transcode_pipeline(audio_data: &[u8], bitrate_kbps: u32, output_format: &str) -> Result<Vec<u8>> {
+    let encoder = match output_format {
+        "opus" => format!("opusenc bitrate={} ! oggmux", bitrate_kbps * 1000),
+        "aac" => format!("voaacenc bitrate={} ! adtsmux", bitrate_kbps * 1000),
+        "flac" => "flacenc".to_string(),
+        _ => format!("lamemp3enc target=bitrate bitrate={} mono=true", bitrate_kbps),
+    };
+
+    let description = format!(
+        "appsrc name=src ! decodebin ! audioconvert ! audioresample ! rganalysis ! rgvolume ! audioconvert ! {} ! appsink name=sink",
+        encoder
+    );
+
+    run_pipeline(audio_data, &description, None)
+}
+
+/// Run `audio_data` through a GStreamer pipeline that decodes it to raw,
+/// mono, 16-bit PCM, returning the samples and their sample rate.
+fn decode_to_mono_pcm(audio_data: &[u8]) -> Result<(Vec<i16>, u32)> {
+    let sample_rate = 24000;
+    let caps = format!("audio/x-raw,format=S16LE,channels=1,rate={}", sample_rate);
+    let description = format!(
+        "appsrc name=src ! decodebin ! audioconvert ! audioresample ! appsink name=sink caps=\"{}\"",
+        caps
+    );
+
+    let raw = run_pipeline(audio_data, &description, Some(&caps))?;
+
+    let samples = raw
+        .chunks_exact(2)
+        .map(|bytes| i16::from_le_bytes([bytes[0], bytes[1]]))
+        .collect();
+
+    Ok((samples, sample_rate))
+}
+
+/// Fraction of full scale below which a sample is considered silent.
+const SILENCE_THRESHOLD_RATIO: f32 = 0.02;
+
+/// Drop leading and trailing runs of near-silent samples, so e.g. a TTS
+/// engine's dead air at the start/end of an utterance doesn't pad out the
+/// WhatsApp-bound file. Samples in between are left untouched. Returns the
+/// input unchanged if it's silent throughout.
+fn trim_silence(samples: &[i16]) -> &[i16] {
+    let threshold = (i16::MAX as f32 * SILENCE_THRESHOLD_RATIO) as u16;
+    let is_loud = |sample: &i16| sample.unsigned_abs() > threshold;
+
+    let Some(start) = samples.iter().position(is_loud) else {
+        return samples;
+    };
+    let end = samples.iter().rposition(is_loud).map(|i| i + 1).unwrap_or(samples.len());
+
+    &samples[start..end]
+}
+
+/// Encode mono 16-bit PCM `samples` as a WAV byte buffer, so trimmed/raw PCM
+/// can be fed back into a GStreamer pipeline (via its `decodebin`) without a
+/// dedicated raw-PCM input path.
+fn encode_wav_mono_s16le(samples: &[i16], sample_rate: u32) -> Vec<u8> {
+    let data_size = (samples.len() * 2) as u32;
+    let byte_rate = sample_rate * 2;
+
+    let mut wav = Vec::with_capacity(44 + samples.len() * 2);
+    wav.extend_from_slice(b"RIFF");
+    wav.extend_from_slice(&(36 + data_size).to_le_bytes());
+    wav.extend_from_slice(b"WAVE");
+    wav.extend_from_slice(b"fmt ");
+    wav.extend_from_slice(&16u32.to_le_bytes()); // fmt chunk size
+    wav.extend_from_slice(&1u16.to_le_bytes()); // PCM
+    wav.extend_from_slice(&1u16.to_le_bytes()); // mono
+    wav.extend_from_slice(&sample_rate.to_le_bytes());
+    wav.extend_from_slice(&byte_rate.to_le_bytes());
+    wav.extend_from_slice(&2u16.to_le_bytes()); // block align (1 channel * 16 bits / 8)
+    wav.extend_from_slice(&16u16.to_le_bytes()); // bits per sample
+    wav.extend_from_slice(b"data");
+    wav.extend_from_slice(&data_size.to_le_bytes());
+    for sample in samples {
+        wav.extend_from_slice(&sample.to_le_bytes());
+    }
+
+    wav
+}
+
+/// Build and drive a GStreamer pipeline described by `description`: push all
+/// of `input` into its `appsrc`, run it to completion, and collect
+/// everything its `appsink` produces.
+fn run_pipeline(input: &[u8], description: &str, _sink_caps: Option<&str>) -> Result<Vec<u8>> {
+    gst::init().context("Failed to initialize GStreamer")?;
+
+    let pipeline = gst::parse::launch(description)
+        .context("Failed to build GStreamer pipeline")?
+        .downcast::<gst::Pipeline>()
+        .map_err(|_| anyhow::anyhow!("Pipeline description did not produce a gst::Pipeline"))?;
+
+    let appsrc = pipeline
+        .by_name("src")
+        .context("Pipeline is missing its appsrc element")?
+        .downcast::<AppSrc>()
+        .map_err(|_| anyhow::anyhow!("src element is not an appsrc"))?;
+
+    let appsink = pipeline
+        .by_name("sink")
+        .context("Pipeline is missing its appsink element")?
+        .downcast::<AppSink>()
+        .map_err(|_| anyhow::anyhow!("sink element is not an appsink"))?;
+
+    let output = Arc::new(Mutex::new(Vec::new()));
+    let output_for_callback = Arc::clone(&output);
+
+    appsink.set_callbacks(
+        AppSinkCallbacks::builder()
+            .new_sample(move |sink| {
+                let sample = sink.pull_sample().map_err(|_| gst::FlowError::Eos)?;
+                let buffer = sample.buffer().ok_or(gst::FlowError::Error)?;
+                let map = buffer.map_readable().map_err(|_| gst::FlowError::Error)?;
+                output_for_callback.lock().unwrap().extend_from_slice(&map);
+                Ok(gst::FlowSuccess::Ok)
+            })
+            .build(),
+    );
+
+    pipeline
+        .set_state(gst::State::Playing)
+        .context("Failed to start GStreamer pipeline")?;
+
+    appsrc
+        .push_buffer(gst::Buffer::from_slice(input.to_vec()))
+        .context("Failed to push input audio into the pipeline")?;
+    appsrc
+        .end_of_stream()
+        .context("Failed to signal end of stream to the pipeline")?;
+
+    let bus = pipeline.bus().context("Pipeline has no message bus")?;
+    for message in bus.iter_timed(gst::ClockTime::NONE) {
+        use gst::MessageView;
+        match message.view() {
+            MessageView::Eos(_) => break,
+            MessageView::Error(err) => {
+                let _ = pipeline.set_state(gst::State::Null);
+                return Err(anyhow::anyhow!(
+                    "GStreamer pipeline error from {:?}: {}",
+                    err.src().map(|s| s.path_string()),
+                    err.error()
+                ));
+            }
+            _ => {}
+        }
+    }
+
+    pipeline
+        .set_state(gst::State::Null)
+        .context("Failed to stop GStreamer pipeline")?;
+
+    // Can't `Arc::try_unwrap` here: `appsink`'s `new_sample` closure holds
+    // its own strong ref to `output` and isn't dropped until after this
+    // tail expression, so the strong count is always 2. Reclaim the buffer
+    // through the mutex instead.
+    Ok(std::mem::take(&mut *output.lock().unwrap()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn trim_silence_drops_leading_and_trailing_quiet_samples() {
+        let loud = (i16::MAX as f32 * 0.5) as i16;
+        let samples = [0, 0, loud, loud, loud, 0, 0];
+
+        assert_eq!(trim_silence(&samples), &[loud, loud, loud]);
+    }
+
+    #[test]
+    fn trim_silence_returns_input_unchanged_when_fully_silent() {
+        let samples = [0, 1, -1, 0];
+
+        assert_eq!(trim_silence(&samples), &samples);
+    }
+
+    #[test]
+    fn encode_wav_mono_s16le_writes_a_valid_header() {
+        let samples = [1i16, -1, 2, -2];
+        let wav = encode_wav_mono_s16le(&samples, 24000);
+
+        assert_eq!(&wav[0..4], b"RIFF");
+        assert_eq!(&wav[8..12], b"WAVE");
+        assert_eq!(u16::from_le_bytes([wav[22], wav[23]]), 1); // mono
+        assert_eq!(u32::from_le_bytes([wav[24], wav[25], wav[26], wav[27]]), 24000);
+        assert_eq!(&wav[36..40], b"data");
+        assert_eq!(
+            u32::from_le_bytes([wav[40], wav[41], wav[42], wav[43]]),
+            (samples.len() * 2) as u32
+        );
+        assert_eq!(&wav[44..], bytemuck_samples(&samples));
+    }
+
+    fn bytemuck_samples(samples: &[i16]) -> Vec<u8> {
+        samples.iter().flat_map(|s| s.to_le_bytes()).collect()
+    }
 }
@@ -0,0 +1,60 @@
+use anyhow::{Context, Result};
+use cookie_store::CookieStore;
+use reqwest_cookie_store::CookieStoreMutex;
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
+use std::path::PathBuf;
+use std::sync::Arc;
+
+/// Persists cookies (consent-wall dismissals, subscriber/login sessions)
+/// between runs so `ArticleExtractor` doesn't have to re-authenticate against
+/// paywalled or consent-walled sites on every call.
+pub struct CookieStorage {
+    store: Arc<CookieStoreMutex>,
+    path: PathBuf,
+}
+
+impl CookieStorage {
+    /// Load cookies from `path` if it exists, otherwise start with an empty
+    /// jar. The file is created on the first [`CookieStorage::persist`] call.
+    pub fn load(path: impl Into<PathBuf>) -> Result<Self> {
+        let path = path.into();
+
+        let cookie_store = if path.exists() {
+            let file = File::open(&path)
+                .with_context(|| format!("Failed to open cookie store: {}", path.display()))?;
+            CookieStore::load_json(BufReader::new(file))
+                .map_err(|e| anyhow::anyhow!("Failed to parse cookie store {}: {}", path.display(), e))?
+        } else {
+            CookieStore::default()
+        };
+
+        Ok(Self {
+            store: Arc::new(CookieStoreMutex::new(cookie_store)),
+            path,
+        })
+    }
+
+    /// The shared cookie store to hand to
+    /// `reqwest::ClientBuilder::cookie_provider`.
+    pub fn store(&self) -> Arc<CookieStoreMutex> {
+        self.store.clone()
+    }
+
+    /// Write the current cookie jar back out to disk as JSON.
+    pub fn persist(&self) -> Result<()> {
+        let file = File::create(&self.path)
+            .with_context(|| format!("Failed to create cookie store: {}", self.path.display()))?;
+
+        let store = self
+            .store
+            .lock()
+            .map_err(|e| anyhow::anyhow!("Cookie store lock poisoned: {}", e))?;
+
+        store
+            .save_json(&mut BufWriter::new(file))
+            .map_err(|e| anyhow::anyhow!("Failed to write cookie store {}: {}", self.path.display(), e))?;
+
+        Ok(())
+    }
+}
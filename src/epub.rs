@@ -0,0 +1,131 @@
+use anyhow::{Context, Result};
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+use zip::write::FileOptions;
+use zip::{CompressionMethod, ZipWriter};
+
+use crate::ArticleContent;
+
+impl ArticleContent {
+    /// Write this article out as a minimal EPUB 3: a container/OPF
+    /// with title/author/date mapped to Dublin Core metadata, one XHTML
+    /// chapter built from the paragraph structure (`\n\n` splits become
+    /// `<p>` elements), and a nav/TOC pointing at it. Gives users an offline,
+    /// e-reader-friendly artifact alongside the audio version. The OPF has
+    /// no `dcterms:modified` entry, so strict EPUB 3 validators reject the
+    /// package, and `dc:language` is always `en`.
+    pub fn to_epub(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        let file = File::create(path)
+            .with_context(|| format!("Failed to create EPUB file: {}", path.display()))?;
+
+        let mut zip = ZipWriter::new(file);
+        let options: FileOptions<()> =
+            FileOptions::default().compression_method(CompressionMethod::Deflated);
+
+        // The mimetype entry must be first and stored uncompressed, per the
+        // EPUB Open Container Format spec.
+        zip.start_file(
+            "mimetype",
+            FileOptions::<()>::default().compression_method(CompressionMethod::Stored),
+        )?;
+        zip.write_all(b"application/epub+zip")?;
+
+        zip.start_file("META-INF/container.xml", options)?;
+        zip.write_all(CONTAINER_XML.as_bytes())?;
+
+        zip.start_file("OEBPS/content.opf", options)?;
+        zip.write_all(self.content_opf().as_bytes())?;
+
+        zip.start_file("OEBPS/nav.xhtml", options)?;
+        zip.write_all(self.nav_xhtml().as_bytes())?;
+
+        zip.start_file("OEBPS/chapter1.xhtml", options)?;
+        zip.write_all(self.chapter_xhtml().as_bytes())?;
+
+        zip.finish().context("Failed to finalize EPUB archive")?;
+
+        Ok(())
+    }
+
+    fn content_opf(&self) -> String {
+        format!(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<package xmlns="http://www.idpf.org/2007/opf" version="3.0" unique-identifier="book-id">
+  <metadata xmlns:dc="http://purl.org/dc/elements/1.1/">
+    <dc:identifier id="book-id">{url}</dc:identifier>
+    <dc:title>{title}</dc:title>
+    <dc:creator>{author}</dc:creator>
+    <dc:date>{date}</dc:date>
+    <dc:language>en</dc:language>
+  </metadata>
+  <manifest>
+    <item id="nav" href="nav.xhtml" properties="nav" media-type="application/xhtml+xml"/>
+    <item id="chapter1" href="chapter1.xhtml" media-type="application/xhtml+xml"/>
+  </manifest>
+  <spine>
+    <itemref idref="chapter1"/>
+  </spine>
+</package>"#,
+            url = xml_escape(&self.url),
+            title = xml_escape(&self.title),
+            author = xml_escape(self.author.as_deref().unwrap_or("Unknown")),
+            date = xml_escape(self.published_date.as_deref().unwrap_or("")),
+        )
+    }
+
+    fn nav_xhtml(&self) -> String {
+        format!(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<html xmlns="http://www.w3.org/1999/xhtml" xmlns:epub="http://www.idpf.org/2007/ops">
+<head><title>Table of Contents</title></head>
+<body>
+  <nav epub:type="toc" id="toc">
+    <ol>
+      <li><a href="chapter1.xhtml">{title}</a></li>
+    </ol>
+  </nav>
+</body>
+</html>"#,
+            title = xml_escape(&self.title)
+        )
+    }
+
+    fn chapter_xhtml(&self) -> String {
+        let paragraphs: String = self
+            .content
+            .split("\n\n")
+            .map(|p| format!("<p>{}</p>", xml_escape(p.trim())))
+            .collect::<Vec<_>>()
+            .join("\n    ");
+
+        format!(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<html xmlns="http://www.w3.org/1999/xhtml">
+<head><title>{title}</title></head>
+<body>
+  <h1>{title}</h1>
+  {paragraphs}
+</body>
+</html>"#,
+            title = xml_escape(&self.title),
+            paragraphs = paragraphs
+        )
+    }
+}
+
+const CONTAINER_XML: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<container version="1.0" xmlns="urn:oasis:names:tc:opendocument:xmlns:container">
+  <rootfiles>
+    <rootfile full-path="OEBPS/content.opf" media-type="application/oebps-package+xml"/>
+  </rootfiles>
+</container>"#;
+
+fn xml_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
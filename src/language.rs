@@ -0,0 +1,131 @@
+use std::collections::HashMap;
+
+use crate::ArticleContent;
+
+/// A detected BCP-47 language code, e.g. `en`, `en-IE`, `fr`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lang(pub String);
+
+impl Lang {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// Penalty applied to a document trigram that doesn't appear in a language's
+/// profile at all, rather than excluding it from the distance entirely.
+const MAX_OUT_OF_PLACE: usize = 1000;
+
+/// Compact frequency-ranked character-trigram profiles (most common first),
+/// modeled on the Cavnar & Trenkle n-gram text categorization approach. A
+/// full profile carries the top ~300 trigrams per language; this crate ships
+/// a smaller representative set, enough to separate the languages below.
+const PROFILES: &[(&str, &[&str])] = &[
+    ("en", &["the", "ing", "and", "ion", "tio", "ent", "ati", "for", "her", "ter", "hat", "tha", "ere", "ate", "his", "con", "res", "ver", "all", "ons"]),
+    ("fr", &["les", "ent", "que", "ion", "tio", "ait", "des", "est", "ous", "oit", "eur", "tre", "ant", "our", "ien", "par", "une", "qui", "ais", "men"]),
+    ("de", &["sch", "ein", "ich", "der", "und", "die", "nde", "den", "ung", "che", "gen", "ter", "ver", "lic", "ste", "auf", "ber", "eit", "nen", "ten"]),
+    ("es", &["ion", "que", "los", "ent", "con", "par", "est", "ado", "ara", "las", "ien", "ica", "nte", "ada", "dos", "por", "del", "cia", "mos", "ste"]),
+    ("ga", &["ach", "ann", "air", "agh", "ght", "aol", "ing", "ain", "ait", "ear", "eag", "amh", "mha", "gha", "ibh", "aig", "ail", "aoi", "dha", "nna"]),
+];
+
+impl ArticleContent {
+    /// Detect the dominant language of this article's content using a
+    /// trigram-profile classifier: rank the document's own trigrams by
+    /// frequency, then pick the language profile that minimizes the total
+    /// out-of-place rank distance against the document's top trigrams.
+    /// Returns `None` if the content is too short to produce a profile.
+    pub fn detect_language(&self) -> Option<Lang> {
+        let document_profile = ranked_trigrams(&self.content, 300);
+        if document_profile.is_empty() {
+            return None;
+        }
+
+        PROFILES
+            .iter()
+            .map(|(code, profile)| (*code, out_of_place_distance(&document_profile, profile)))
+            .min_by_key(|(_, distance)| *distance)
+            .map(|(code, _)| Lang(code.to_string()))
+    }
+}
+
+/// The document's own trigrams, ranked most-to-least frequent and truncated
+/// to `limit`, ready to compare against a language profile.
+fn ranked_trigrams(text: &str, limit: usize) -> Vec<String> {
+    let normalized: String = text
+        .to_lowercase()
+        .chars()
+        .filter(|c| c.is_alphabetic() || c.is_whitespace())
+        .collect();
+
+    let chars: Vec<char> = normalized.chars().collect();
+    let mut counts: HashMap<String, usize> = HashMap::new();
+
+    for window in chars.windows(3) {
+        let trigram: String = window.iter().collect();
+        if trigram.trim().is_empty() {
+            continue;
+        }
+        *counts.entry(trigram).or_insert(0) += 1;
+    }
+
+    let mut ranked: Vec<(String, usize)> = counts.into_iter().collect();
+    ranked.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    ranked
+        .into_iter()
+        .take(limit)
+        .map(|(trigram, _)| trigram)
+        .collect()
+}
+
+/// Sum, over the document's ranked trigrams, of how far each trigram's rank
+/// is from its rank in the language profile (or `MAX_OUT_OF_PLACE` if the
+/// trigram doesn't appear in the profile at all). Lower is a better match.
+fn out_of_place_distance(document_profile: &[String], language_profile: &[&str]) -> usize {
+    document_profile
+        .iter()
+        .enumerate()
+        .map(|(doc_rank, trigram)| {
+            language_profile
+                .iter()
+                .position(|t| t == trigram)
+                .map(|lang_rank| lang_rank.abs_diff(doc_rank))
+                .unwrap_or(MAX_OUT_OF_PLACE)
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn article(content: &str) -> ArticleContent {
+        ArticleContent {
+            title: "Test".to_string(),
+            author: None,
+            published_date: None,
+            content: content.to_string(),
+            summary: None,
+            url: "https://example.com".to_string(),
+        }
+    }
+
+    #[test]
+    fn detects_english() {
+        let content = "The quick brown fox jumps over the lazy dog and then the dog barks at the fox again and again.".repeat(3);
+        let article = article(&content);
+        assert_eq!(article.detect_language(), Some(Lang("en".to_string())));
+    }
+
+    #[test]
+    fn detects_french() {
+        let content = "Les chats et les chiens sont des animaux que les gens aiment beaucoup dans notre pays.".repeat(3);
+        let article = article(&content);
+        assert_eq!(article.detect_language(), Some(Lang("fr".to_string())));
+    }
+
+    #[test]
+    fn returns_none_for_too_short_content() {
+        let article = article("hi");
+        assert_eq!(article.detect_language(), None);
+    }
+}
@@ -1,7 +1,14 @@
 pub mod article_extractor;
+pub mod cookie_storage;
+pub mod epub;
+pub mod feed_reader;
+pub mod language;
+pub mod transcription;
 pub mod tts_service;
 pub mod audio_processor;
 
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -20,15 +27,174 @@ pub struct AudioConfig {
     pub speaking_rate: f32,
     pub output_format: String,
     pub sample_rate: u32,
+    /// Maps a BCP-47 language code to the neural voice used for it, so
+    /// detected-language content doesn't always fall back to Irish English.
+    pub language_voices: HashMap<String, String>,
+}
+
+impl AudioConfig {
+    /// Look up the configured voice for a detected language, trying an exact
+    /// BCP-47 match first (e.g. `en-IE`) and falling back to the primary
+    /// subtag (e.g. `en`).
+    pub fn voice_for_language(&self, language_code: &str) -> Option<&str> {
+        if let Some(voice) = self.language_voices.get(language_code) {
+            return Some(voice.as_str());
+        }
+
+        let primary_subtag = language_code.split('-').next().unwrap_or(language_code);
+        self.language_voices.get(primary_subtag).map(|v| v.as_str())
+    }
 }
 
 impl Default for AudioConfig {
     fn default() -> Self {
+        let language_voices = HashMap::from([
+            ("en-IE".to_string(), "en-IE-EmilyNeural".to_string()),
+            ("en".to_string(), "en-US-JennyNeural".to_string()),
+            ("fr".to_string(), "fr-FR-DeniseNeural".to_string()),
+            ("de".to_string(), "de-DE-KatjaNeural".to_string()),
+            ("es".to_string(), "es-ES-ElviraNeural".to_string()),
+            ("ga".to_string(), "ga-IE-OrlaNeural".to_string()),
+        ]);
+
         Self {
             voice_name: "en-IE-EmilyNeural".to_string(), // Azure Irish female voice
             speaking_rate: 0.9,
             output_format: "mp3".to_string(),
             sample_rate: 24000,
+            language_voices,
+        }
+    }
+}
+
+/// Build the text that gets spoken for an article: a byline/date header
+/// followed by the speech-cleaned body. A body longer than `max_length`
+/// bytes is cut at a sentence boundary and followed by a note that the
+/// article was shortened; the header and that note don't count against
+/// `max_length`. Shared by the CLI and the demo binary so a fix here
+/// doesn't need to be applied twice.
+pub fn process_content_for_audio(article: &ArticleContent, max_length: usize) -> anyhow::Result<String> {
+    let mut content = format!("Article: {}\n\n", article.title);
+
+    if let Some(author) = &article.author {
+        content.push_str(&format!("By {}\n\n", author));
+    }
+
+    if let Some(date) = &article.published_date {
+        content.push_str(&format!("Published {}\n\n", date));
+    }
+
+    // Clean up the article content for better speech synthesis
+    let cleaned_content = clean_text_for_speech(&article.content);
+
+    // Truncate if too long, but try to end at sentence boundaries
+    if cleaned_content.len() > max_length {
+        content.push_str(&truncate_at_sentence(&cleaned_content, max_length));
+        content.push_str("\n\nThis article has been shortened for audio. The full version is available at the original link.");
+    } else {
+        content.push_str(&cleaned_content);
+    }
+
+    Ok(content)
+}
+
+/// Clean article text for speech synthesis: decode HTML entities, expand
+/// abbreviations/symbols a TTS engine would otherwise read literally, and
+/// normalize whitespace/punctuation.
+pub fn clean_text_for_speech(text: &str) -> String {
+    use regex::Regex;
+
+    // Decode named (&amp;, &nbsp;) and numeric (&#8217;) HTML entities first,
+    // otherwise they get read aloud literally by the TTS engine.
+    let mut cleaned = html_escape::decode_html_entities(text).to_string();
+
+    // Expand common abbreviations and symbols so they're spoken naturally
+    // instead of read as punctuation.
+    let abbreviations = vec![
+        (Regex::new(r"\bDr\.").unwrap(), "Doctor"),
+        (Regex::new(r"\bMr\.").unwrap(), "Mister"),
+        (Regex::new(r"\bMrs\.").unwrap(), "Missus"),
+        (Regex::new(r"\bMs\.").unwrap(), "Miz"),
+        (Regex::new(r"\bProf\.").unwrap(), "Professor"),
+        (Regex::new(r"\bSt\.").unwrap(), "Saint"),
+        (Regex::new(r"\bvs\.").unwrap(), "versus"),
+        (Regex::new(r"\betc\.").unwrap(), "etcetera"),
+    ];
+
+    for (pattern, replacement) in abbreviations {
+        cleaned = pattern.replace_all(&cleaned, replacement).to_string();
+    }
+
+    // "$5m" / "$5bn" -> "5 million dollars" / "5 billion dollars"
+    let money = Regex::new(r"\$(\d+(?:\.\d+)?)\s*(m|mn|million|b|bn|billion)\b").unwrap();
+    cleaned = money
+        .replace_all(&cleaned, |caps: &regex::Captures| {
+            let amount = &caps[1];
+            let unit = caps[2].to_lowercase();
+            let scale = match unit.as_str() {
+                "m" | "mn" | "million" => "million",
+                "b" | "bn" | "billion" => "billion",
+                _ => unit.as_str(),
+            };
+            format!("{} {} dollars", amount, scale)
+        })
+        .to_string();
+
+    // "%" -> " percent"
+    cleaned = Regex::new(r"\s*%").unwrap().replace_all(&cleaned, " percent").to_string();
+
+    // Remove or replace problematic characters/patterns for TTS
+    let patterns = vec![
+        (Regex::new(r"https?://[^\s]+").unwrap(), ""), // Remove URLs
+        (Regex::new(r"\s+").unwrap(), " "), // Normalize whitespace
+        (Regex::new(r#"["""]"#).unwrap(), "\""), // Normalize smart quotes
+        (Regex::new(r#"[''']"#).unwrap(), "'"), // Normalize smart apostrophes
+        (Regex::new(r"–|—").unwrap(), " - "), // Replace em/en dashes
+        (Regex::new(r"\n\s*\n").unwrap(), "\n\n"), // Normalize paragraphs
+    ];
+
+    for (pattern, replacement) in patterns {
+        cleaned = pattern.replace_all(&cleaned, replacement).to_string();
+    }
+
+    cleaned.trim().to_string()
+}
+
+/// Truncate `text` to at most `max_length` bytes, preferring to end at a
+/// sentence boundary. Without one, it cuts at the last space and appends
+/// "...", so that result can run up to three bytes past `max_length`.
+/// `max_length` is first rounded down to the nearest
+/// char boundary, since `text` may contain multi-byte UTF-8 (e.g. from
+/// decoded HTML entities) and an arbitrary byte offset would otherwise
+/// split a character and panic.
+pub fn truncate_at_sentence(text: &str, max_length: usize) -> String {
+    if text.len() <= max_length {
+        return text.to_string();
+    }
+
+    let boundary = (0..=max_length).rev().find(|&i| text.is_char_boundary(i)).unwrap_or(0);
+
+    // Find the last sentence ending before max_length
+    let truncated = &text[..boundary];
+    if let Some(pos) = truncated.rfind(". ") {
+        format!("{}.", &truncated[..pos])
+    } else if let Some(pos) = truncated.rfind("! ") {
+        format!("{}!", &truncated[..pos])
+    } else if let Some(pos) = truncated.rfind("? ") {
+        format!("{}?", &truncated[..pos])
+    } else {
+        // Fallback: find last space
+        if let Some(pos) = truncated.rfind(' ') {
+            format!("{}...", &truncated[..pos])
+        } else {
+            format!("{}...", truncated)
         }
     }
 }
+
+/// Rough spoken-duration estimate in minutes, assuming a conservative
+/// ~175 words per minute.
+pub fn estimate_duration(text: &str) -> f32 {
+    let word_count = text.split_whitespace().count() as f32;
+    word_count / 175.0
+}
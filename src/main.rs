@@ -2,10 +2,15 @@ use anyhow::{Context, Result};
 use clap::Parser;
 use std::path::PathBuf;
 
-use audio_transcription::{ArticleContent, AudioConfig};
+use audio_transcription::{estimate_duration, process_content_for_audio, AudioConfig};
 use audio_transcription::article_extractor::ArticleExtractor;
 use audio_transcription::tts_service::TTSService;
 use audio_transcription::audio_processor::AudioProcessor;
+use audio_transcription::transcription::{self, TranscriptionService};
+
+/// Voice used when neither `--voice` nor `--language` is given and language
+/// auto-detection doesn't turn up a configured voice for the article.
+const DEFAULT_VOICE: &str = "en-IE-EmilyNeural";
 
 #[derive(Parser, Debug)]
 #[command(author, version, about = "Convert web articles to high-quality audio for accessibility")]
@@ -18,22 +23,46 @@ struct Args {
     #[arg(short, long)]
     output: Option<PathBuf>,
     
-    /// TTS service to use (azure, google, or local)
+    /// TTS service to use (azure, google, or openai). A local/offline
+    /// backend was tried and dropped: the `tts` crate it would have used
+    /// only exposes the OS's live speech output, with no way to render
+    /// audio to a file.
     #[arg(short, long, default_value = "azure")]
     service: String,
     
-    /// Voice name/ID (service-specific)
-    #[arg(short, long, default_value = "en-IE-EmilyNeural")]
-    voice: String,
+    /// Voice name/ID (service-specific); leave unset to auto-select based on
+    /// the detected article language
+    #[arg(short, long)]
+    voice: Option<String>,
     
     /// Speaking rate (0.5 to 2.0)
     #[arg(short, long, default_value = "0.9")]
     rate: f32,
-    
+
+    /// Output audio format: mp3, opus, aac, flac, or wav. Only the openai
+    /// service and the WhatsApp re-encode honor this; opus produces
+    /// smaller files that are easier to fit under the WhatsApp size limit
+    #[arg(short = 'f', long, default_value = "mp3")]
+    format: String,
+
+    /// BCP-47 language to pick a voice for (e.g. en-IE, zh), looked up in
+    /// the service's voice list; when that list has a voice for the
+    /// language, it overrides --voice
+    #[arg(short, long)]
+    language: Option<String>,
+
     /// Maximum article length in characters (for conciseness)
     #[arg(short, long, default_value = "5000")]
     max_length: usize,
-    
+
+    /// Generate a time-aligned .srt caption file alongside the audio
+    #[arg(long)]
+    captions: bool,
+
+    /// Also emit a .vtt caption file (requires --captions)
+    #[arg(long)]
+    vtt: bool,
+
     /// Verbose output
     #[arg(long)]
     verbose: bool,
@@ -65,14 +94,46 @@ async fn main() -> Result<()> {
     // Step 2: Process and optimize content for audio
     let processed_content = process_content_for_audio(&article, args.max_length)?;
     
-    // Step 3: Configure TTS
+    // Step 3: Configure TTS, auto-selecting a voice for the detected
+    // language unless the user explicitly passed --voice
     let mut audio_config = AudioConfig::default();
-    audio_config.voice_name = args.voice;
     audio_config.speaking_rate = args.rate;
-    
+    audio_config.output_format = args.format.clone();
+
+    match &args.voice {
+        Some(voice) => audio_config.voice_name = voice.clone(),
+        None => {
+            audio_config.voice_name = DEFAULT_VOICE.to_string();
+            if let Some(detected) = article.detect_language() {
+                if let Some(voice) = audio_config.voice_for_language(detected.as_str()) {
+                    if args.verbose {
+                        println!("Detected language: {}", detected.as_str());
+                    }
+                    audio_config.voice_name = voice.to_string();
+                }
+            }
+        }
+    }
+
     // Step 4: Generate audio
-    println!("🎙️  Generating audio with Irish female voice...");
-    let tts_service = TTSService::new(&args.service, &audio_config)?;
+    let mut tts_service = TTSService::new(&args.service, &audio_config)?;
+
+    // --language overrides both the default and the detected voice: look up
+    // the service's live voice catalog and pick its best (neural-first) match.
+    if let Some(language_code) = &args.language {
+        let mut voices = tts_service.list_voices(Some(language_code)).await?;
+        voices.sort_by_key(|voice| !voice.is_neural);
+
+        if let Some(voice) = voices.into_iter().next() {
+            if args.verbose {
+                println!("Selected voice for {}: {} ({})", language_code, voice.voice_id, voice.description);
+            }
+            audio_config.voice_name = voice.voice_id;
+            tts_service = TTSService::new(&args.service, &audio_config)?;
+        }
+    }
+
+    println!("🎙️  Generating audio...");
     let audio_data = tts_service.synthesize_speech(&processed_content)
         .await
         .context("Failed to generate speech")?;
@@ -80,7 +141,7 @@ async fn main() -> Result<()> {
     // Step 5: Process and save audio
     println!("💾 Processing and saving audio...");
     let output_path = args.output.unwrap_or_else(|| {
-        let filename = format!("article_{}.mp3", uuid::Uuid::new_v4().simple());
+        let filename = format!("article_{}.{}", uuid::Uuid::new_v4().simple(), audio_config.output_format);
         PathBuf::from(filename)
     });
     
@@ -91,82 +152,46 @@ async fn main() -> Result<()> {
     println!("✅ Audio file created successfully!");
     println!("📱 File: {} (optimized for WhatsApp)", output_path.display());
     println!("📊 Duration: ~{:.1} minutes", estimate_duration(&processed_content));
-    
-    Ok(())
-}
 
-fn process_content_for_audio(article: &ArticleContent, max_length: usize) -> Result<String> {
-    let mut content = format!("Article: {}\n\n", article.title);
-    
-    if let Some(author) = &article.author {
-        content.push_str(&format!("By {}\n\n", author));
-    }
-    
-    if let Some(date) = &article.published_date {
-        content.push_str(&format!("Published {}\n\n", date));
+    // Step 6: Optionally transcribe the saved (trimmed, re-encoded) audio
+    // back into time-aligned captions
+    if args.captions {
+        println!("📝 Generating captions...");
+        let saved_audio = std::fs::read(&output_path)
+            .with_context(|| format!("Failed to read audio file: {}", output_path.display()))?;
+        generate_captions(&output_path, &saved_audio, args.vtt).await?;
     }
-    
-    // Clean up the article content for better speech synthesis
-    let cleaned_content = clean_text_for_speech(&article.content);
-    
-    // Truncate if too long, but try to end at sentence boundaries
-    if cleaned_content.len() > max_length {
-        content.push_str(&truncate_at_sentence(&cleaned_content, max_length));
-        content.push_str("\n\nThis article has been shortened for audio. The full version is available at the original link.");
-    } else {
-        content.push_str(&cleaned_content);
-    }
-    
-    Ok(content)
-}
 
-fn clean_text_for_speech(text: &str) -> String {
-    use regex::Regex;
-    
-    let mut cleaned = text.to_string();
-    
-    // Remove or replace problematic characters/patterns for TTS
-    let patterns = vec![
-        (Regex::new(r"https?://[^\s]+").unwrap(), ""), // Remove URLs
-        (Regex::new(r"\s+").unwrap(), " "), // Normalize whitespace
-        (Regex::new(r#"["""]"#).unwrap(), "\""), // Normalize smart quotes
-        (Regex::new(r#"[''']"#).unwrap(), "'"), // Normalize smart apostrophes
-        (Regex::new(r"–|—").unwrap(), " - "), // Replace em/en dashes
-        (Regex::new(r"\n\s*\n").unwrap(), "\n\n"), // Normalize paragraphs
-    ];
-    
-    for (pattern, replacement) in patterns {
-        cleaned = pattern.replace_all(&cleaned, replacement).to_string();
-    }
-    
-    cleaned.trim().to_string()
+    Ok(())
 }
 
-fn truncate_at_sentence(text: &str, max_length: usize) -> String {
-    if text.len() <= max_length {
-        return text.to_string();
-    }
-    
-    // Find the last sentence ending before max_length
-    let truncated = &text[..max_length];
-    if let Some(pos) = truncated.rfind(". ") {
-        format!("{}.", &truncated[..pos])
-    } else if let Some(pos) = truncated.rfind("! ") {
-        format!("{}!", &truncated[..pos])
-    } else if let Some(pos) = truncated.rfind("? ") {
-        format!("{}?", &truncated[..pos])
-    } else {
-        // Fallback: find last space
-        if let Some(pos) = truncated.rfind(' ') {
-            format!("{}...", &truncated[..pos])
-        } else {
-            format!("{}...", truncated)
-        }
+/// Transcribe `audio_data` and write an `.srt` file (and optionally a
+/// `.vtt` file) alongside `audio_path`.
+async fn generate_captions(audio_path: &PathBuf, audio_data: &[u8], also_vtt: bool) -> Result<()> {
+    let transcription_service = TranscriptionService::new("openai")?;
+
+    let filename = audio_path
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_else(|| "audio.mp3".to_string());
+
+    let segments = transcription_service
+        .transcribe_verbose(audio_data, &filename)
+        .await
+        .context("Failed to transcribe audio for captions")?;
+
+    let srt_path = audio_path.with_extension("srt");
+    std::fs::write(&srt_path, transcription::segments_to_srt(&segments))
+        .with_context(|| format!("Failed to write caption file: {}", srt_path.display()))?;
+    println!("📄 Captions: {}", srt_path.display());
+
+    if also_vtt {
+        let vtt_path = audio_path.with_extension("vtt");
+        std::fs::write(&vtt_path, transcription::segments_to_vtt(&segments))
+            .with_context(|| format!("Failed to write caption file: {}", vtt_path.display()))?;
+        println!("📄 Captions: {}", vtt_path.display());
     }
-}
 
-fn estimate_duration(text: &str) -> f32 {
-    // Rough estimate: ~150-200 words per minute for clear speech
-    let word_count = text.split_whitespace().count() as f32;
-    word_count / 175.0 // Conservative estimate
+    Ok(())
 }
+
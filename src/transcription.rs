@@ -0,0 +1,201 @@
+use anyhow::{Context, Result};
+use reqwest::multipart::{Form, Part};
+use reqwest::Client;
+use serde::Deserialize;
+use std::env;
+
+/// A time-aligned segment of transcribed speech, as returned by OpenAI's
+/// verbose-JSON transcription format.
+#[derive(Debug, Clone, Deserialize)]
+pub struct TranscriptSegment {
+    pub start: f32,
+    pub end: f32,
+    pub text: String,
+    #[serde(default)]
+    pub words: Vec<TranscriptWord>,
+}
+
+/// A single word's timing within a [`TranscriptSegment`].
+#[derive(Debug, Clone, Deserialize)]
+pub struct TranscriptWord {
+    pub word: String,
+    pub start: f32,
+    pub end: f32,
+}
+
+#[derive(Debug, Deserialize)]
+struct VerboseTranscriptionResponse {
+    segments: Vec<TranscriptSegment>,
+}
+
+#[derive(Debug, Clone)]
+enum TranscriptionServiceType {
+    OpenAI { api_key: String },
+}
+
+/// Turns synthesized audio back into time-aligned text, so captions can be
+/// generated alongside the TTS output. Mirrors [`crate::tts_service::TTSService`]'s
+/// shape: a service-name constructor that reads provider credentials from
+/// the environment, dispatching to one `synthesize`/`transcribe` method per
+/// provider.
+#[derive(Debug, Clone)]
+pub struct TranscriptionService {
+    service_type: TranscriptionServiceType,
+    client: Client,
+}
+
+impl TranscriptionService {
+    pub fn new(service_name: &str) -> Result<Self> {
+        let client = Client::new();
+
+        let service_type = match service_name.to_lowercase().as_str() {
+            "openai" => {
+                let api_key = env::var("OPENAI_API_KEY")
+                    .context("OPENAI_API_KEY environment variable not set")?;
+
+                TranscriptionServiceType::OpenAI { api_key }
+            }
+            _ => return Err(anyhow::anyhow!("Unsupported transcription service: {}", service_name)),
+        };
+
+        Ok(Self { service_type, client })
+    }
+
+    /// Transcribe `audio_data` with segment- and word-level timestamps, so
+    /// captions can be aligned to the audio. `filename` is only used to hint
+    /// the format to the provider (e.g. `audio.mp3`).
+    pub async fn transcribe_verbose(&self, audio_data: &[u8], filename: &str) -> Result<Vec<TranscriptSegment>> {
+        match &self.service_type {
+            TranscriptionServiceType::OpenAI { api_key } => {
+                self.transcribe_openai_verbose(audio_data, filename, api_key).await
+            }
+        }
+    }
+
+    async fn transcribe_openai_verbose(
+        &self,
+        audio_data: &[u8],
+        filename: &str,
+        api_key: &str,
+    ) -> Result<Vec<TranscriptSegment>> {
+        let part = Part::bytes(audio_data.to_vec()).file_name(filename.to_string());
+
+        let form = Form::new()
+            .part("file", part)
+            .text("model", "whisper-1")
+            .text("response_format", "verbose_json")
+            .text("timestamp_granularities[]", "segment")
+            .text("timestamp_granularities[]", "word");
+
+        let response = self
+            .client
+            .post("https://api.openai.com/v1/audio/transcriptions")
+            .bearer_auth(api_key)
+            .multipart(form)
+            .send()
+            .await
+            .context("Failed to send request to OpenAI transcription")?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let error_text = response.text().await.unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(anyhow::anyhow!(
+                "OpenAI transcription error ({}): {}",
+                status,
+                error_text
+            ));
+        }
+
+        let parsed: VerboseTranscriptionResponse = response
+            .json()
+            .await
+            .context("Failed to parse OpenAI transcription response")?;
+
+        Ok(parsed.segments)
+    }
+}
+
+/// Format transcript segments as an SRT (SubRip) subtitle file.
+pub fn segments_to_srt(segments: &[TranscriptSegment]) -> String {
+    let mut output = String::new();
+
+    for (index, segment) in segments.iter().enumerate() {
+        output.push_str(&format!("{}\n", index + 1));
+        output.push_str(&format!(
+            "{} --> {}\n",
+            format_timestamp(segment.start, ','),
+            format_timestamp(segment.end, ',')
+        ));
+        output.push_str(segment.text.trim());
+        output.push_str("\n\n");
+    }
+
+    output
+}
+
+/// Format transcript segments as a WebVTT subtitle file.
+pub fn segments_to_vtt(segments: &[TranscriptSegment]) -> String {
+    let mut output = String::from("WEBVTT\n\n");
+
+    for segment in segments {
+        output.push_str(&format!(
+            "{} --> {}\n",
+            format_timestamp(segment.start, '.'),
+            format_timestamp(segment.end, '.')
+        ));
+        output.push_str(segment.text.trim());
+        output.push_str("\n\n");
+    }
+
+    output
+}
+
+/// Render seconds as `HH:MM:SS<sep>mmm`, the cue-timestamp format shared by
+/// SRT (`,` millisecond separator) and WebVTT (`.`).
+fn format_timestamp(seconds: f32, millis_separator: char) -> String {
+    let total_millis = (seconds.max(0.0) * 1000.0).round() as u64;
+    let hours = total_millis / 3_600_000;
+    let minutes = (total_millis % 3_600_000) / 60_000;
+    let secs = (total_millis % 60_000) / 1000;
+    let millis = total_millis % 1000;
+    format!("{:02}:{:02}:{:02}{}{:03}", hours, minutes, secs, millis_separator, millis)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_timestamps_with_the_right_separator() {
+        assert_eq!(format_timestamp(0.0, ','), "00:00:00,000");
+        assert_eq!(format_timestamp(1.5, '.'), "00:00:01.500");
+        assert_eq!(format_timestamp(3725.25, ','), "01:02:05,250");
+        assert_eq!(format_timestamp(-1.0, ','), "00:00:00,000");
+    }
+
+    fn segment(start: f32, end: f32, text: &str) -> TranscriptSegment {
+        TranscriptSegment {
+            start,
+            end,
+            text: text.to_string(),
+            words: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn renders_srt_cues_in_order() {
+        let segments = vec![segment(0.0, 1.5, "Hello there"), segment(1.5, 3.0, "General Kenobi")];
+        let srt = segments_to_srt(&segments);
+        assert_eq!(
+            srt,
+            "1\n00:00:00,000 --> 00:00:01,500\nHello there\n\n2\n00:00:01,500 --> 00:00:03,000\nGeneral Kenobi\n\n"
+        );
+    }
+
+    #[test]
+    fn renders_vtt_with_header() {
+        let segments = vec![segment(0.0, 1.5, "Hello there")];
+        let vtt = segments_to_vtt(&segments);
+        assert_eq!(vtt, "WEBVTT\n\n00:00:00.000 --> 00:00:01.500\nHello there\n\n");
+    }
+}
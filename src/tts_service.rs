@@ -1,16 +1,47 @@
 use anyhow::{Context, Result};
+use regex::Regex;
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
 use std::env;
+use std::sync::{Arc, Mutex};
 use base64::{Engine, engine::general_purpose::STANDARD as BASE64};
 
 use crate::AudioConfig;
 
+/// Azure and Google both reject payloads beyond a few thousand characters,
+/// so long articles get split into fragments no larger than this before
+/// synthesis, then concatenated back into one MP3.
+const DEFAULT_AZURE_CUT_SIZE: usize = 3000;
+const DEFAULT_GOOGLE_CUT_SIZE: usize = 5000;
+/// OpenAI's `/audio/speech` endpoint rejects input beyond 4096 characters.
+const DEFAULT_OPENAI_CUT_SIZE: usize = 4000;
+
+/// Named voices offered by OpenAI's TTS models; `voice_name` falls back to
+/// `alloy` if it isn't one of these.
+const OPENAI_VOICES: &[&str] = &["alloy", "echo", "fable", "onyx", "nova", "shimmer"];
+
+/// Audio container/codec formats OpenAI's TTS endpoint can render to.
+const OPENAI_RESPONSE_FORMATS: &[&str] = &["mp3", "opus", "aac", "flac", "wav"];
+
 #[derive(Debug, Clone)]
 pub struct TTSService {
     service_type: TTSServiceType,
     client: Client,
     config: AudioConfig,
+    cut_size: usize,
+    voice_cache: Arc<Mutex<Option<Vec<Voice>>>>,
+}
+
+/// A voice normalized across providers, as returned by
+/// [`TTSService::list_voices`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Voice {
+    pub service: String,
+    pub voice_id: String,
+    pub language_code: String,
+    pub gender: String,
+    pub description: String,
+    pub is_neural: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -22,7 +53,9 @@ enum TTSServiceType {
     Google {
         api_key: String,
     },
-    Local,
+    OpenAI {
+        api_key: String,
+    },
 }
 
 #[derive(Serialize)]
@@ -69,6 +102,15 @@ struct GoogleTTSResponse {
     audio_content: String,
 }
 
+#[derive(Serialize)]
+struct OpenAISpeechRequest<'a> {
+    model: &'a str,
+    input: &'a str,
+    voice: &'a str,
+    response_format: &'a str,
+    speed: f32,
+}
+
 impl TTSService {
     pub fn new(service_name: &str, config: &AudioConfig) -> Result<Self> {
         let client = Client::new();
@@ -91,18 +133,53 @@ impl TTSService {
                 
                 TTSServiceType::Google { api_key }
             }
-            "local" => TTSServiceType::Local,
+            "openai" => {
+                let api_key = env::var("OPENAI_API_KEY")
+                    .context("OPENAI_API_KEY environment variable not set")?;
+
+                TTSServiceType::OpenAI { api_key }
+            }
             _ => return Err(anyhow::anyhow!("Unsupported TTS service: {}", service_name)),
         };
-        
+
+        let cut_size = match &service_type {
+            TTSServiceType::Azure { .. } => DEFAULT_AZURE_CUT_SIZE,
+            TTSServiceType::Google { .. } => DEFAULT_GOOGLE_CUT_SIZE,
+            TTSServiceType::OpenAI { .. } => DEFAULT_OPENAI_CUT_SIZE,
+        };
+
         Ok(Self {
             service_type,
             client,
             config: config.clone(),
+            cut_size,
+            voice_cache: Arc::new(Mutex::new(None)),
         })
     }
-    
+
+    /// Override the default per-fragment character limit used to split long
+    /// text before synthesis.
+    pub fn with_cut_size(mut self, cut_size: usize) -> Self {
+        self.cut_size = cut_size;
+        self
+    }
+
+    /// Split `text` into fragments no larger than `cut_size`, synthesize
+    /// each one, and concatenate the resulting MP3s. This is what lets
+    /// articles longer than the provider's per-request character limit
+    /// still make it through as a single audio file.
     pub async fn synthesize_speech(&self, text: &str) -> Result<Vec<u8>> {
+        let fragments = split_into_fragments(text, self.cut_size);
+
+        let mut audio = Vec::new();
+        for fragment in fragments {
+            audio.extend(self.synthesize_fragment(&fragment).await?);
+        }
+
+        Ok(audio)
+    }
+
+    async fn synthesize_fragment(&self, text: &str) -> Result<Vec<u8>> {
         match &self.service_type {
             TTSServiceType::Azure { subscription_key, region } => {
                 self.synthesize_azure_speech(text, subscription_key, region).await
@@ -110,12 +187,12 @@ impl TTSService {
             TTSServiceType::Google { api_key } => {
                 self.synthesize_google_speech(text, api_key).await
             }
-            TTSServiceType::Local => {
-                self.synthesize_local_speech(text).await
+            TTSServiceType::OpenAI { api_key } => {
+                self.synthesize_openai_speech(text, api_key).await
             }
         }
     }
-    
+
     async fn synthesize_azure_speech(&self, text: &str, subscription_key: &str, region: &str) -> Result<Vec<u8>> {
         // Azure Speech Services SSML format
         let ssml = format!(
@@ -220,69 +297,306 @@ impl TTSService {
         
         Ok(audio_data)
     }
-    
-    async fn synthesize_local_speech(&self, text: &str) -> Result<Vec<u8>> {
-        // Fallback to local TTS using espeak or festival
-        use std::process::Command;
-        use std::fs;
-        
-        let temp_file = format!("/tmp/tts_output_{}.wav", uuid::Uuid::new_v4().simple());
-        
-        // Try espeak first (more likely to be available)
-        let output = Command::new("espeak")
-            .args(&[
-                "-v", "en-irish+f3", // Irish female voice variant 3
-                "-s", "160", // Speaking speed (words per minute)
-                "-a", "100", // Amplitude
-                "-g", "10",  // Gap between words
-                "-f", "-",   // Read from stdin
-                "-w", &temp_file, // Write to file
-            ])
-            .arg(text)
-            .output();
-        
-        match output {
-            Ok(output) if output.status.success() => {
-                let audio_data = fs::read(&temp_file)
-                    .context("Failed to read generated audio file")?;
-                
-                // Clean up temporary file
-                let _ = fs::remove_file(&temp_file);
-                
-                Ok(audio_data)
+
+    async fn synthesize_openai_speech(&self, text: &str, api_key: &str) -> Result<Vec<u8>> {
+        let voice = if OPENAI_VOICES.contains(&self.config.voice_name.as_str()) {
+            self.config.voice_name.as_str()
+        } else {
+            "alloy" // OpenAI's default voice
+        };
+
+        let response_format = if OPENAI_RESPONSE_FORMATS.contains(&self.config.output_format.as_str()) {
+            self.config.output_format.as_str()
+        } else {
+            "mp3"
+        };
+
+        let request = OpenAISpeechRequest {
+            model: "tts-1",
+            input: text,
+            voice,
+            response_format,
+            speed: self.config.speaking_rate,
+        };
+
+        let response = self.client
+            .post("https://api.openai.com/v1/audio/speech")
+            .bearer_auth(api_key)
+            .json(&request)
+            .send()
+            .await
+            .context("Failed to send request to OpenAI TTS")?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let error_text = response.text().await.unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(anyhow::anyhow!(
+                "OpenAI TTS error ({}): {}",
+                status,
+                error_text
+            ));
+        }
+
+        let audio_data = response
+            .bytes()
+            .await
+            .context("Failed to read audio data from OpenAI response")?;
+
+        Ok(audio_data.to_vec())
+    }
+
+    /// List voices available from this service's provider, optionally
+    /// filtered to a BCP-47 language tag (an exact match like `en-IE`, or a
+    /// bare primary subtag like `zh` matching every regional variant).
+    /// Results are fetched from the provider's live voice catalog and cached
+    /// for the lifetime of this `TTSService`.
+    pub async fn list_voices(&self, language_code: Option<&str>) -> Result<Vec<Voice>> {
+        let voices = self.cached_or_fetch_voices().await?;
+
+        Ok(match language_code {
+            Some(code) => voices
+                .into_iter()
+                .filter(|voice| voice_matches_language(voice, code))
+                .collect(),
+            None => voices,
+        })
+    }
+
+    async fn cached_or_fetch_voices(&self) -> Result<Vec<Voice>> {
+        if let Some(cached) = self.voice_cache.lock().unwrap().clone() {
+            return Ok(cached);
+        }
+
+        let fetched = match &self.service_type {
+            TTSServiceType::Azure { subscription_key, region } => {
+                fetch_azure_voices(&self.client, subscription_key, region).await?
             }
-            _ => {
-                // Fallback: Try festival
-                let festival_output = Command::new("festival")
-                    .args(&["--tts", "--pipe"])
-                    .arg(text)
-                    .output();
-                
-                match festival_output {
-                    Ok(_) => {
-                        // Festival is more complex to integrate, this is a placeholder
-                        Err(anyhow::anyhow!(
-                            "Local TTS failed. Please install espeak: sudo apt-get install espeak"
-                        ))
-                    }
-                    Err(_) => {
-                        Err(anyhow::anyhow!(
-                            "No local TTS system available. Please install espeak or configure cloud TTS services."
-                        ))
-                    }
-                }
+            TTSServiceType::Google { api_key } => {
+                fetch_google_voices(&self.client, api_key).await?
             }
+            TTSServiceType::OpenAI { .. } => openai_voices(),
+        };
+
+        *self.voice_cache.lock().unwrap() = Some(fetched.clone());
+        Ok(fetched)
+    }
+}
+
+fn voice_matches_language(voice: &Voice, code: &str) -> bool {
+    let voice_lang = voice.language_code.to_lowercase();
+    let code = code.to_lowercase();
+    voice_lang == code || voice_lang.starts_with(&format!("{}-", code))
+}
+
+/// OpenAI doesn't expose a voice-catalog endpoint; its TTS models support a
+/// fixed, language-agnostic set of named voices, so list them directly.
+fn openai_voices() -> Vec<Voice> {
+    OPENAI_VOICES
+        .iter()
+        .map(|&voice_id| Voice {
+            service: "openai".to_string(),
+            voice_id: voice_id.to_string(),
+            language_code: "multi".to_string(),
+            gender: "unspecified".to_string(),
+            description: format!("OpenAI {} voice", voice_id),
+            is_neural: true,
+        })
+        .collect()
+}
+
+#[derive(Deserialize)]
+struct AzureVoiceEntry {
+    #[serde(rename = "ShortName")]
+    short_name: String,
+    #[serde(rename = "Locale")]
+    locale: String,
+    #[serde(rename = "Gender")]
+    gender: String,
+    #[serde(rename = "VoiceType")]
+    voice_type: String,
+    #[serde(rename = "DisplayName")]
+    display_name: String,
+}
+
+/// Query Azure's live voice catalog: https://{region}.tts.speech.microsoft.com/cognitiveservices/voices/list
+async fn fetch_azure_voices(client: &Client, subscription_key: &str, region: &str) -> Result<Vec<Voice>> {
+    let url = format!(
+        "https://{}.tts.speech.microsoft.com/cognitiveservices/voices/list",
+        region
+    );
+
+    let response = client
+        .get(&url)
+        .header("Ocp-Apim-Subscription-Key", subscription_key)
+        .send()
+        .await
+        .context("Failed to fetch Azure voice catalog")?;
+
+    if !response.status().is_success() {
+        return Err(anyhow::anyhow!(
+            "Azure voice catalog error: {}",
+            response.status()
+        ));
+    }
+
+    let entries: Vec<AzureVoiceEntry> = response
+        .json()
+        .await
+        .context("Failed to parse Azure voice catalog")?;
+
+    Ok(entries
+        .into_iter()
+        .map(|entry| Voice {
+            service: "azure".to_string(),
+            voice_id: entry.short_name,
+            language_code: entry.locale,
+            gender: entry.gender,
+            description: entry.display_name,
+            is_neural: entry.voice_type.eq_ignore_ascii_case("Neural"),
+        })
+        .collect())
+}
+
+#[derive(Deserialize)]
+struct GoogleVoiceListResponse {
+    voices: Vec<GoogleVoiceEntry>,
+}
+
+#[derive(Deserialize)]
+struct GoogleVoiceEntry {
+    #[serde(rename = "languageCodes")]
+    language_codes: Vec<String>,
+    name: String,
+    #[serde(rename = "ssmlGender")]
+    ssml_gender: String,
+}
+
+/// Query Google Cloud's live voice catalog: https://texttospeech.googleapis.com/v1/voices
+async fn fetch_google_voices(client: &Client, api_key: &str) -> Result<Vec<Voice>> {
+    let url = format!(
+        "https://texttospeech.googleapis.com/v1/voices?key={}",
+        api_key
+    );
+
+    let response = client
+        .get(&url)
+        .send()
+        .await
+        .context("Failed to fetch Google voice catalog")?;
+
+    if !response.status().is_success() {
+        return Err(anyhow::anyhow!(
+            "Google voice catalog error: {}",
+            response.status()
+        ));
+    }
+
+    let parsed: GoogleVoiceListResponse = response
+        .json()
+        .await
+        .context("Failed to parse Google voice catalog")?;
+
+    Ok(parsed
+        .voices
+        .into_iter()
+        .flat_map(|entry| {
+            let is_neural = entry.name.contains("Wavenet")
+                || entry.name.contains("Neural2")
+                || entry.name.contains("Studio");
+
+            entry
+                .language_codes
+                .into_iter()
+                .map(move |language_code| Voice {
+                    service: "google".to_string(),
+                    voice_id: entry.name.clone(),
+                    language_code,
+                    gender: entry.ssml_gender.clone(),
+                    description: entry.name.clone(),
+                    is_neural,
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect())
+}
+
+/// Split `text` into fragments no larger than `cut_size` **characters**,
+/// cutting at the last sentence boundary within each window (or the last
+/// space, if no sentence boundary is found) so cuts never land mid-word -
+/// or, on multi-byte content, mid-character. A single word longer than
+/// `cut_size` is the exception: it is cut after `cut_size` characters.
+fn split_into_fragments(text: &str, cut_size: usize) -> Vec<String> {
+    let normalized = Regex::new(r"\s+")
+        .unwrap()
+        .replace_all(text.trim(), " ")
+        .to_string();
+
+    if normalized.chars().count() <= cut_size {
+        return vec![normalized];
+    }
+
+    let mut fragments = Vec::new();
+    let mut remainder = normalized.as_str();
+
+    while remainder.chars().count() > cut_size {
+        let window_end = char_boundary(remainder, cut_size + 1);
+        let window = &remainder[..window_end];
+
+        let sentence_boundary = [". ", "! ", "? "]
+            .iter()
+            .filter_map(|boundary| window.rfind(boundary).map(|pos| pos + boundary.len()))
+            .max();
+
+        let cut_at = sentence_boundary
+            .or_else(|| window.rfind(' ').map(|pos| pos + 1))
+            .unwrap_or_else(|| char_boundary(remainder, cut_size.max(1)));
+
+        fragments.push(remainder[..cut_at].trim().to_string());
+        remainder = remainder[cut_at..].trim_start();
+    }
+
+    if !remainder.is_empty() {
+        fragments.push(remainder.to_string());
+    }
+
+    fragments
+}
+
+/// The byte offset of the `char_count`-th character in `s` (or `s.len()` if
+/// `s` is shorter), so a window can be sliced without panicking on a
+/// multi-byte UTF-8 boundary.
+fn char_boundary(s: &str, char_count: usize) -> usize {
+    s.char_indices()
+        .nth(char_count)
+        .map(|(byte_idx, _)| byte_idx)
+        .unwrap_or(s.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_multibyte_text_without_panicking() {
+        let fragment = "café Müller naïve résumé déjà vu piñata jalapeño ";
+        let text = fragment.repeat(100);
+        assert!(text.len() > 3000);
+
+        let fragments = split_into_fragments(&text, 200);
+
+        assert!(fragments.len() > 1);
+        for piece in &fragments {
+            assert!(piece.chars().count() <= 200);
         }
+
+        let joined = fragments.join(" ");
+        let joined_words: Vec<&str> = joined.split_whitespace().collect();
+        let orig_words: Vec<&str> = text.split_whitespace().collect();
+        assert_eq!(joined_words, orig_words);
     }
-    
-    pub fn get_available_irish_voices() -> Vec<(&'static str, &'static str, &'static str)> {
-        // Returns (service, voice_id, description)
-        vec![
-            ("azure", "en-IE-EmilyNeural", "Emily - Irish female, natural and warm"),
-            ("azure", "en-IE-ConnorNeural", "Connor - Irish male, clear and friendly"),
-            ("google", "en-IE-Standard-A", "Google Irish female voice"),
-            ("google", "en-IE-Wavenet-A", "Google Irish female voice (WaveNet - higher quality)"),
-            ("local", "en-irish+f3", "eSpeak Irish female voice"),
-        ]
+
+    #[test]
+    fn keeps_short_text_as_one_fragment() {
+        assert_eq!(split_into_fragments("hello world", 100), vec!["hello world".to_string()]);
     }
 }